@@ -1,6 +1,8 @@
 use std::borrow::{BorrowFrom, ToOwned};
 use std::collections::BTreeMap;
 
+use time;
+
 use rustc_serialize::base64;
 use rustc_serialize::base64::ToBase64;
 use rustc_serialize::json;
@@ -78,6 +80,41 @@ impl Claims {
         self.raw.get(key)
     }
 
+    /// Set who issued the JWT.
+    pub fn set_iss(&mut self, iss: &str) {
+        self.raw.insert("iss".to_string(), iss.to_json());
+    }
+
+    /// Set the subject of the JWT.
+    pub fn set_sub(&mut self, sub: &str) {
+        self.raw.insert("sub".to_string(), sub.to_json());
+    }
+
+    /// Set the list of recipients the JWT is intended for.
+    pub fn set_aud<A: ToAudience>(&mut self, aud: A) {
+        self.raw.insert("aud".to_string(), aud.to_audience_json());
+    }
+
+    /// Set the time after which the JWT is considered invalid (POSIX time).
+    pub fn set_exp(&mut self, exp: f64) {
+        self.raw.insert("exp".to_string(), exp.to_json());
+    }
+
+    /// Set the time before which the JWT is considered invalid (POSIX time).
+    pub fn set_nbf(&mut self, nbf: f64) {
+        self.raw.insert("nbf".to_string(), nbf.to_json());
+    }
+
+    /// Set the time the JWT was issued (POSIX time).
+    pub fn set_iat(&mut self, iat: f64) {
+        self.raw.insert("iat".to_string(), iat.to_json());
+    }
+
+    /// Set the JWT ID.
+    pub fn set_jti(&mut self, jti: &str) {
+        self.raw.insert("jti".to_string(), jti.to_json());
+    }
+
     /// Add a (potentially unregistered) claim. Note that this can lead
     /// to an invalid JWT if the semantics of the claim don't match the
     /// JWT specification.
@@ -111,3 +148,82 @@ impl ToBase64 for Claims {
         self.to_json().to_string().as_bytes().to_base64(config)
     }
 }
+
+/// Anything that can populate the `aud` claim: either a single recipient
+/// or a list of recipients.
+pub trait ToAudience {
+    fn to_audience_json(&self) -> json::Json;
+}
+
+impl<'a> ToAudience for &'a str {
+    fn to_audience_json(&self) -> json::Json {
+        // `aud()` only ever reads the array form, so a single audience is
+        // still wrapped in a one-element list.
+        vec![*self].to_json()
+    }
+}
+
+impl<'a> ToAudience for &'a [&'a str] {
+    fn to_audience_json(&self) -> json::Json {
+        self.to_json()
+    }
+}
+
+/// Builds a `Claims` set with typed setters instead of `insert_unsafe`.
+/// `iat` is filled in automatically from the current time when the
+/// builder is created.
+pub struct ClaimsBuilder {
+    claims: Claims,
+}
+
+impl Claims {
+    /// Start building a claim set. Sets `iat` to the current time.
+    pub fn builder() -> ClaimsBuilder {
+        let mut claims = Claims::new();
+        claims.set_iat(time::get_time().sec as f64);
+        ClaimsBuilder { claims: claims }
+    }
+}
+
+impl ClaimsBuilder {
+    /// Set who issued the JWT.
+    pub fn iss(mut self, iss: &str) -> ClaimsBuilder {
+        self.claims.set_iss(iss);
+        self
+    }
+
+    /// Set the subject of the JWT.
+    pub fn sub(mut self, sub: &str) -> ClaimsBuilder {
+        self.claims.set_sub(sub);
+        self
+    }
+
+    /// Set the list of recipients the JWT is intended for.
+    pub fn aud<A: ToAudience>(mut self, aud: A) -> ClaimsBuilder {
+        self.claims.set_aud(aud);
+        self
+    }
+
+    /// Set the time after which the JWT is considered invalid (POSIX time).
+    pub fn exp(mut self, exp: f64) -> ClaimsBuilder {
+        self.claims.set_exp(exp);
+        self
+    }
+
+    /// Set the time before which the JWT is considered invalid (POSIX time).
+    pub fn nbf(mut self, nbf: f64) -> ClaimsBuilder {
+        self.claims.set_nbf(nbf);
+        self
+    }
+
+    /// Set the JWT ID.
+    pub fn jti(mut self, jti: &str) -> ClaimsBuilder {
+        self.claims.set_jti(jti);
+        self
+    }
+
+    /// Finish building and return the `Claims`.
+    pub fn build(self) -> Claims {
+        self.claims
+    }
+}